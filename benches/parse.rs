@@ -0,0 +1,50 @@
+use criterion::{black_box, criterion_group, criterion_main, BenchmarkId, Criterion, Throughput};
+use netrc_rs::Netrc;
+
+fn machines(n: usize, sep: &str) -> String {
+    (0..n)
+        .map(|i| {
+            format!(
+                "machine host{i}.example.com{sep}login user{i}{sep}password p@ssw0rd{i}\n",
+                i = i,
+                sep = sep
+            )
+        })
+        .collect()
+}
+
+fn macdefs(n: usize) -> String {
+    (0..n)
+        .map(|i| {
+            format!(
+                "machine host{i}.example.com login user{i}\nmacdef upload{i}\ncd /pub/tests\nbin\nput file{i}.tar.gz\nquit\n\n",
+                i = i
+            )
+        })
+        .collect()
+}
+
+fn bench_parse(c: &mut Criterion) {
+    let mut group = c.benchmark_group("parse");
+    for &n in &[1_000, 10_000, 100_000] {
+        let inputs = [
+            ("spaces", machines(n, " ")),
+            ("tabs", machines(n, "\t")),
+            ("macdefs", macdefs(n)),
+        ];
+        for (name, input) in inputs.iter() {
+            group.throughput(Throughput::Bytes(input.len() as u64));
+            group.bench_with_input(BenchmarkId::new(*name, n), input, |b, input| {
+                b.iter(|| Netrc::parse(black_box(input), false).unwrap())
+            });
+        }
+    }
+    group.finish();
+}
+
+criterion_group! {
+    name = benches;
+    config = Criterion::default().sample_size(10);
+    targets = bench_parse
+}
+criterion_main!(benches);